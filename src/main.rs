@@ -1,32 +1,40 @@
 use anyhow::{Context, Result};
 use chrono::Local;
 use clap::Parser;
-use console::style;
+use console::{style, Key, Term};
+use futures::stream::{FuturesUnordered, StreamExt};
 use indicatif::{MultiProgress, ProgressBar, ProgressStyle};
 use std::env;
 use std::io;
 use std::io::Write;
+use std::sync::Arc;
 use tokio::time::{sleep, Duration};
 use tracing::{error, info};
 
 mod config;
 mod logger;
 mod notification;
+mod plugins;
+mod run_log;
 mod steps;
+mod sudo;
+mod timing;
 mod user_input;
 
 use config::Config;
 use notification::send_notification;
+use run_log::RunLog;
 use steps::{CommandStep, UpdaterStep};
+use timing::{StepDurations, StepTimer};
 use user_input::confirm;
 struct Updater {
     interactive: bool,
     quiet: bool,
     steps: Vec<Box<dyn UpdaterStep + Send + Sync>>,
     multi: MultiProgress,
-    #[allow(dead_code)]
-    config: Config,
     stats: UpdateStats,
+    step_durations: StepDurations,
+    run_log: Arc<RunLog>,
 }
 
 #[derive(Debug, Clone)]
@@ -60,18 +68,30 @@ impl Updater {
         quiet: bool,
         steps: Vec<Box<dyn UpdaterStep + Send + Sync>>,
         config: Config,
+        run_log: Arc<RunLog>,
     ) -> Self {
+        let steps: Vec<_> = steps
+            .into_iter()
+            .filter(|step| !config.skip_steps.iter().any(|skip| skip == step.id()))
+            .collect();
         let total_steps = steps.len();
         Updater {
             interactive,
             quiet,
             steps,
             multi: MultiProgress::new(),
-            config,
             stats: UpdateStats::new(total_steps),
+            step_durations: Arc::new(std::sync::Mutex::new(Vec::new())),
+            run_log,
         }
     }
 
+    /// Whether any retained step (after `config.skip_steps` filtering) shells
+    /// out through `sudo`, used to decide whether to arm the keep-alive loop.
+    fn needs_sudo(&self) -> bool {
+        self.steps.iter().any(|step| step.uses_sudo())
+    }
+
     async fn run(mut self) -> Result<()> {
         let total_steps = self.steps.len();
 
@@ -79,83 +99,42 @@ impl Updater {
             println!("🔧 Starting {} maintenance steps...\n", total_steps);
         }
 
-        for (step_idx, step) in self.steps.into_iter().enumerate() {
-            let desc = step.description();
-            let step_num = step_idx + 1;
-
-            if self.interactive && !confirm(desc)? {
-                if !self.quiet {
-                    println!(
-                        "⏭️ [{}/{}] {}",
-                        step_num,
-                        total_steps,
-                        style("Skipped.").yellow()
-                    );
-                }
-                info!("Skipped: {}", desc);
-                self.stats.skipped_steps += 1;
-                continue;
+        let numbered_steps: Vec<(usize, Box<dyn UpdaterStep + Send + Sync>)> =
+            std::mem::take(&mut self.steps).into_iter().enumerate().collect();
+
+        if self.interactive || self.quiet {
+            // Confirmation prompts and the quiet one-line-per-step output both
+            // only make sense run one step at a time, in order.
+            for (step_idx, step) in numbered_steps {
+                self.run_sequential(step_idx + 1, total_steps, step.as_ref())
+                    .await?;
             }
+        } else {
+            let (parallel, exclusive): (Vec<_>, Vec<_>) = numbered_steps
+                .into_iter()
+                .partition(|(_, step)| !step.exclusive());
 
-            if self.quiet {
-                print!("\r🔧 [{}/{}] {}...", step_num, total_steps, desc);
-                io::stdout().flush().ok();
-            } else {
+            let mut pending = FuturesUnordered::new();
+            for (step_idx, step) in parallel {
                 let pb = self.multi.add(ProgressBar::new_spinner());
-                pb.set_message(
-                    style(format!("[{}/{}] {}...", step_num, total_steps, desc))
-                        .white()
-                        .to_string(),
-                );
-                pb.enable_steady_tick(Duration::from_millis(120));
-                pb.set_style(
-                    ProgressStyle::with_template("{spinner:.green.bold} {msg}")
-                        .unwrap()
-                        .tick_strings(&["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "✅"]),
-                );
-
-                info!("Starting: {}", desc);
-
-                if let Err(e) = step.run(&pb).await {
-                    pb.finish_with_message(
-                        style(format!(
-                            "[{}/{}] ❌ Failed: {}",
-                            step_num, total_steps, desc
-                        ))
-                        .red()
-                        .bold()
-                        .to_string(),
-                    );
-                    error!("Failed: {}: {:?}", desc, e);
-                    self.stats.failed_steps += 1;
-                    continue;
-                }
-
-                pb.finish_with_message(
-                    style(format!("[{}/{}] ✅ {}", step_num, total_steps, desc))
-                        .green()
-                        .bold()
-                        .to_string(),
-                );
-                info!("Finished: {}", desc);
-                self.stats.completed_steps += 1;
-                sleep(Duration::from_millis(150)).await;
+                pending.push(run_concurrent(
+                    step_idx + 1,
+                    total_steps,
+                    step,
+                    pb,
+                    self.step_durations.clone(),
+                ));
+            }
+            while let Some(outcome) = pending.next().await {
+                self.apply_outcome(outcome);
             }
 
-            if self.quiet {
-                if let Err(_e) = step.run(&ProgressBar::hidden()).await {
-                    print!(" ❌");
-                    self.stats.failed_steps += 1;
-                } else {
-                    print!(" ✅");
-                    self.stats.completed_steps += 1;
-                }
-                io::stdout().flush().ok();
+            for (step_idx, step) in exclusive {
+                self.run_sequential(step_idx + 1, total_steps, step.as_ref())
+                    .await?;
             }
         }
 
-        if self.quiet {}
-
         info!("Update completed: {:?}", self.stats);
 
         let duration = self.stats.duration();
@@ -194,8 +173,208 @@ impl Updater {
             seconds
         );
 
+        if let Ok(durations) = self.step_durations.lock() {
+            println!("   {} Step timings:", style("⏱️").blue());
+            for (desc, duration) in durations.iter() {
+                println!("      {:>6.1}s  {}", duration.as_secs_f64(), desc);
+            }
+            self.run_log.record_step_durations(&durations);
+        }
+        println!(
+            "   {} Full log: {}",
+            style("📄").blue(),
+            self.run_log.path().display()
+        );
+
+        Ok(())
+    }
+
+    /// Runs one step to completion before returning, printing the
+    /// interactive-confirm or quiet one-liner output as appropriate.
+    async fn run_sequential(
+        &mut self,
+        step_num: usize,
+        total_steps: usize,
+        step: &(dyn UpdaterStep + Send + Sync),
+    ) -> Result<()> {
+        let desc = step.description();
+
+        let missing = steps::missing_requirements(step.requires());
+        if !missing.is_empty() {
+            if !self.quiet {
+                println!(
+                    "⏭️ [{}/{}] {} {}",
+                    step_num,
+                    total_steps,
+                    style("not installed:").yellow(),
+                    missing.join(", ")
+                );
+            }
+            info!("Skipped (not installed): {}: {}", desc, missing.join(", "));
+            self.stats.skipped_steps += 1;
+            return Ok(());
+        }
+
+        if self.interactive && !confirm(desc)? {
+            if !self.quiet {
+                println!(
+                    "⏭️ [{}/{}] {}",
+                    step_num,
+                    total_steps,
+                    style("Skipped.").yellow()
+                );
+            }
+            info!("Skipped: {}", desc);
+            self.stats.skipped_steps += 1;
+            return Ok(());
+        }
+
+        if self.quiet {
+            print!("\r🔧 [{}/{}] {}...", step_num, total_steps, desc);
+            io::stdout().flush().ok();
+
+            let timer = StepTimer::start(desc.to_string(), self.step_durations.clone());
+            if let Err(_e) = step.run(&ProgressBar::hidden()).await {
+                print!(" ❌");
+                self.stats.failed_steps += 1;
+            } else {
+                print!(" ✅");
+                self.stats.completed_steps += 1;
+            }
+            drop(timer);
+            io::stdout().flush().ok();
+            return Ok(());
+        }
+
+        let pb = self.multi.add(ProgressBar::new_spinner());
+        pb.set_message(
+            style(format!("[{}/{}] {}...", step_num, total_steps, desc))
+                .white()
+                .to_string(),
+        );
+        pb.enable_steady_tick(Duration::from_millis(120));
+        pb.set_style(
+            ProgressStyle::with_template("{spinner:.green.bold} {msg}")
+                .unwrap()
+                .tick_strings(&["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "✅"]),
+        );
+
+        info!("Starting: {}", desc);
+
+        let timer = StepTimer::start(desc.to_string(), self.step_durations.clone());
+        let result = step.run(&pb).await;
+        drop(timer);
+
+        if let Err(e) = result {
+            pb.finish_with_message(
+                style(format!(
+                    "[{}/{}] ❌ Failed: {}",
+                    step_num, total_steps, desc
+                ))
+                .red()
+                .bold()
+                .to_string(),
+            );
+            error!("Failed: {}: {:?}", desc, e);
+            self.stats.failed_steps += 1;
+            return Ok(());
+        }
+
+        pb.finish_with_message(
+            style(format!("[{}/{}] ✅ {}", step_num, total_steps, desc))
+                .green()
+                .bold()
+                .to_string(),
+        );
+        info!("Finished: {}", desc);
+        self.stats.completed_steps += 1;
+        sleep(Duration::from_millis(150)).await;
+
         Ok(())
     }
+
+    fn apply_outcome(&mut self, outcome: StepOutcome) {
+        match outcome {
+            StepOutcome::Completed => self.stats.completed_steps += 1,
+            StepOutcome::Skipped => self.stats.skipped_steps += 1,
+            StepOutcome::Failed => self.stats.failed_steps += 1,
+        }
+    }
+}
+
+enum StepOutcome {
+    Completed,
+    Skipped,
+    Failed,
+}
+
+/// Runs a single non-exclusive step against its own progress bar so it can be
+/// driven alongside other steps in a `FuturesUnordered`.
+async fn run_concurrent(
+    step_num: usize,
+    total_steps: usize,
+    step: Box<dyn UpdaterStep + Send + Sync>,
+    pb: ProgressBar,
+    durations: StepDurations,
+) -> StepOutcome {
+    let desc = step.description().to_string();
+
+    let missing = steps::missing_requirements(step.requires());
+    if !missing.is_empty() {
+        pb.finish_with_message(
+            style(format!(
+                "[{}/{}] ⏭️ not installed: {}",
+                step_num,
+                total_steps,
+                missing.join(", ")
+            ))
+            .yellow()
+            .to_string(),
+        );
+        info!("Skipped (not installed): {}: {}", desc, missing.join(", "));
+        return StepOutcome::Skipped;
+    }
+
+    pb.set_message(
+        style(format!("[{}/{}] {}...", step_num, total_steps, desc))
+            .white()
+            .to_string(),
+    );
+    pb.enable_steady_tick(Duration::from_millis(120));
+    pb.set_style(
+        ProgressStyle::with_template("{spinner:.green.bold} {msg}")
+            .unwrap()
+            .tick_strings(&["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "✅"]),
+    );
+
+    info!("Starting: {}", desc);
+
+    let timer = StepTimer::start(desc.clone(), durations);
+    let result = step.run(&pb).await;
+    drop(timer);
+
+    if let Err(e) = result {
+        pb.finish_with_message(
+            style(format!(
+                "[{}/{}] ❌ Failed: {}",
+                step_num, total_steps, desc
+            ))
+            .red()
+            .bold()
+            .to_string(),
+        );
+        error!("Failed: {}: {:?}", desc, e);
+        return StepOutcome::Failed;
+    }
+
+    pb.finish_with_message(
+        style(format!("[{}/{}] ✅ {}", step_num, total_steps, desc))
+            .green()
+            .bold()
+            .to_string(),
+    );
+    info!("Finished: {}", desc);
+    StepOutcome::Completed
 }
 
 #[derive(Parser)]
@@ -209,8 +388,15 @@ struct Args {
     interactive: bool,
     #[arg(short = 'q', long = "quiet")]
     quiet: bool,
+    /// List discovered plugins from the plugins directory and exit.
+    #[arg(long = "list-plugins")]
+    list_plugins: bool,
 }
-pub async fn run_command_with_output(cmd: String, pb: ProgressBar) -> anyhow::Result<()> {
+pub async fn run_command_with_output(
+    cmd: String,
+    pb: ProgressBar,
+    run_log: Arc<RunLog>,
+) -> anyhow::Result<()> {
     // Use shell to execute complex commands with pipes, redirections, etc.
     let output = tokio::process::Command::new("sh")
         .arg("-c")
@@ -222,6 +408,8 @@ pub async fn run_command_with_output(cmd: String, pb: ProgressBar) -> anyhow::Re
     let stdout = String::from_utf8_lossy(&output.stdout);
     let stderr = String::from_utf8_lossy(&output.stderr);
 
+    run_log.record_command(&cmd, output.status, &stdout, &stderr);
+
     // Log stdout wenn vorhanden
     if !stdout.trim().is_empty() {
         info!("Command `{}` stdout: {}", cmd, stdout.trim());
@@ -265,6 +453,41 @@ pub async fn run_command_with_output(cmd: String, pb: ProgressBar) -> anyhow::Re
     Ok(())
 }
 
+/// Post-run prompt letting the user reboot, drop into a shell to inspect
+/// results, or just quit. Loops until a recognized key is pressed.
+fn post_run_menu() -> Result<()> {
+    let term = Term::stdout();
+
+    loop {
+        print!("\n(R)eboot / (S)hell / (Q)uit: ");
+        io::stdout().flush().ok();
+
+        match term.read_key()? {
+            Key::Char('r') | Key::Char('R') => {
+                println!("r");
+                info!("Rebooting at user's request.");
+                std::process::Command::new("osascript")
+                    .args(["-e", "tell app \"System Events\" to restart"])
+                    .status()
+                    .context("Failed to trigger reboot")?;
+                return Ok(());
+            }
+            Key::Char('s') | Key::Char('S') => {
+                println!("s");
+                let shell = env::var("SHELL").unwrap_or_else(|_| "/bin/zsh".to_string());
+                println!("{}", style("Dropping into a shell. Exit it to return.").dim());
+                std::process::Command::new(shell).status().ok();
+                return Ok(());
+            }
+            Key::Char('q') | Key::Char('Q') | Key::Enter => {
+                println!("q");
+                return Ok(());
+            }
+            _ => continue,
+        }
+    }
+}
+
 #[tokio::main]
 async fn main() -> Result<()> {
     // Initialize the logger
@@ -274,6 +497,30 @@ async fn main() -> Result<()> {
 
     let config = Config::load().context("Failed to load configuration")?;
 
+    if args.list_plugins {
+        let plugin_paths = plugins::default_plugins_dir()
+            .map(|dir| plugins::discover(&dir))
+            .unwrap_or_default();
+
+        if plugin_paths.is_empty() {
+            println!("No plugins found.");
+        } else {
+            println!("Discovered plugins:");
+            for path in &plugin_paths {
+                let name = plugins::plugin_name(path);
+                let skipped = config.skip_steps.iter().any(|skip| skip == &name);
+                println!(
+                    "  {}{}",
+                    name,
+                    if skipped { " (skipped by config)" } else { "" }
+                );
+            }
+        }
+        return Ok(());
+    }
+
+    let run_log = Arc::new(RunLog::create().context("Failed to create run log")?);
+
     print!("\x1B[2J\x1B[1;1H");
     io::stdout().flush().ok();
 
@@ -285,51 +532,81 @@ async fn main() -> Result<()> {
             .bold()
     );
 
-    let run_command = |cmd: String, pb: ProgressBar| {
-        Box::pin(run_command_with_output(cmd, pb))
-            as std::pin::Pin<Box<dyn std::future::Future<Output = anyhow::Result<()>> + Send>>
+    let run_command = {
+        let run_log = run_log.clone();
+        move |cmd: String, pb: ProgressBar| {
+            Box::pin(run_command_with_output(cmd, pb, run_log.clone()))
+                as std::pin::Pin<Box<dyn std::future::Future<Output = anyhow::Result<()>> + Send>>
+        }
     };
+    let brew_steps: Vec<Box<dyn UpdaterStep + Send + Sync>> = steps::brew_variants()
+        .into_iter()
+        .map(|variant| {
+            let brew = variant.binary_name();
+            Box::new(
+                CommandStep::new(
+                    variant.label().to_string(),
+                    vec![
+                        format!("{brew} update"),
+                        format!("{brew} upgrade"),
+                        format!("{brew} cleanup"),
+                    ],
+                    run_command.clone(),
+                )
+                .requires(vec![brew])
+                .id(variant.skip_id()),
+            ) as Box<dyn UpdaterStep + Send + Sync>
+        })
+        .collect();
+
     let update_steps: Vec<Box<dyn UpdaterStep + Send + Sync>> = vec![
-        Box::new(CommandStep::new(
-            "Updating Homebrew",
-            vec!["brew update", "brew upgrade", "brew cleanup"],
-            run_command,
-        )),
-        Box::new(CommandStep::new(
-            "Upgrading App Store apps",
-            vec!["mas upgrade"],
-            run_command,
-        )),
-        Box::new(CommandStep::new(
-            "Updating npm packages",
-            vec![
-                "npm outdated -g --parseable --depth=0 | cut -d: -f4 | xargs -I {} npm install -g {}",
-            ],
-            run_command,
-        )),
-        Box::new(CommandStep::new(
-            "Updating Composer packages",
-            vec!["composer global update"],
-            run_command,
-        )),
+        Box::new(
+            CommandStep::new("Upgrading App Store apps", vec!["mas upgrade"], run_command.clone())
+                .requires(vec!["mas"]),
+        ),
+        Box::new(
+            CommandStep::new(
+                "Updating npm packages",
+                vec![
+                    "npm outdated -g --parseable --depth=0 | cut -d: -f4 | xargs -I {} npm install -g {}",
+                ],
+                run_command.clone(),
+            )
+            .requires(vec!["npm"]),
+        ),
+        Box::new(
+            CommandStep::new(
+                "Updating Composer packages",
+                vec!["composer global update"],
+                run_command.clone(),
+            )
+            .requires(vec!["composer"]),
+        ),
         Box::new(CommandStep::new(
             "Installing system updates",
             vec!["softwareupdate -ia"],
-            run_command,
-        )),
-        Box::new(CommandStep::new(
-            "Updating Ruby gems",
-            vec!["gem update --user-install", "gem cleanup"],
-            run_command,
+            run_command.clone(),
         )),
-        Box::new(CommandStep::new(
-            "Updating oh-my-zsh",
-            vec![&format!(
+        Box::new(
+            CommandStep::new(
+                "Updating Ruby gems",
+                vec!["gem update --user-install", "gem cleanup"],
+                run_command.clone(),
+            )
+            .requires(vec!["gem"]),
+        ),
+        Box::new({
+            let upgrade_script = format!(
                 "{}/.oh-my-zsh/tools/upgrade.sh",
                 env::home_dir().unwrap().display()
-            )],
-            run_command,
-        )),
+            );
+            CommandStep::new(
+                "Updating oh-my-zsh".to_string(),
+                vec![upgrade_script.clone()],
+                run_command.clone(),
+            )
+            .requires(vec![upgrade_script])
+        }),
     ];
 
     let maintenance_steps: Vec<Box<dyn UpdaterStep + Send + Sync>> = vec![
@@ -339,16 +616,25 @@ async fn main() -> Result<()> {
                 "sudo dscacheutil -flushcache",
                 "sudo killall -HUP mDNSResponder",
             ],
-            run_command,
+            run_command.clone(),
         )),
         Box::new(CommandStep::new(
-            "Cleaning download folders",
+            "Cleaning download folders".to_string(),
             vec![
-                "[ -d ~/Downloads ] && find ~/Downloads -type f -mtime +30 -delete 2>/dev/null || true",
-                "[ -d ~/Desktop ] && find ~/Desktop -name '*.dmg' -mtime +7 -delete 2>/dev/null || true",
-                "[ -d ~/Desktop ] && find ~/Desktop -name 'Screenshot*' -mtime +14 -delete 2>/dev/null || true",
+                format!(
+                    "[ -d ~/Downloads ] && find ~/Downloads -type f -mtime +{} -delete 2>/dev/null || true",
+                    config.cleanup_settings.downloads_days_old
+                ),
+                format!(
+                    "[ -d ~/Desktop ] && find ~/Desktop -name '*.dmg' -mtime +{} -delete 2>/dev/null || true",
+                    config.cleanup_settings.dmg_files_days_old
+                ),
+                format!(
+                    "[ -d ~/Desktop ] && find ~/Desktop -name 'Screenshot*' -mtime +{} -delete 2>/dev/null || true",
+                    config.cleanup_settings.screenshots_days_old
+                ),
             ],
-            run_command,
+            run_command.clone(),
         )),
         Box::new(CommandStep::new(
             "Optimizing disk space",
@@ -356,22 +642,38 @@ async fn main() -> Result<()> {
                 "sudo tmutil thinlocalsnapshots / 10000000000 4 2>/dev/null || true",
                 "sudo purge",
             ],
-            run_command,
+            run_command.clone(),
         )),
         Box::new(CommandStep::new(
-            "Clearing logs and temp files",
-            vec![
-                "sudo rm -rf /private/var/log/asl/*.asl 2>/dev/null || true",
-                "sudo rm -rf /Library/Logs/DiagnosticReports/* 2>/dev/null || true",
-                "sudo rm -rf /var/folders/*/*/*/C/* 2>/dev/null || true",
-                "rm -rf ~/Library/Application\\ Support/CrashReporter/* 2>/dev/null || true",
-            ],
-            run_command,
+            "Clearing logs and temp files".to_string(),
+            {
+                let mut cmds: Vec<String> = vec![];
+                if config.cleanup_settings.clear_system_logs {
+                    cmds.extend([
+                        "sudo rm -rf /private/var/log/asl/*.asl 2>/dev/null || true".to_string(),
+                        "sudo rm -rf /Library/Logs/DiagnosticReports/* 2>/dev/null || true"
+                            .to_string(),
+                        "sudo rm -rf /var/folders/*/*/*/C/* 2>/dev/null || true".to_string(),
+                        "rm -rf ~/Library/Application\\ Support/CrashReporter/* 2>/dev/null || true"
+                            .to_string(),
+                    ]);
+                }
+                if config.cleanup_settings.clear_browser_caches {
+                    cmds.extend([
+                        "rm -rf ~/Library/Caches/com.apple.Safari/* 2>/dev/null || true"
+                            .to_string(),
+                        "rm -rf ~/Library/Caches/Google/Chrome/* 2>/dev/null || true".to_string(),
+                        "rm -rf ~/Library/Caches/Firefox/* 2>/dev/null || true".to_string(),
+                    ]);
+                }
+                cmds
+            },
+            run_command.clone(),
         )),
         Box::new(CommandStep::new(
             "Updating Mac App Store CLI",
             vec!["mas outdated"],
-            run_command,
+            run_command.clone(),
         )),
         Box::new(CommandStep::new(
             "Optimizing Spotlight index",
@@ -380,7 +682,7 @@ async fn main() -> Result<()> {
                 "sudo mdutil -E / 2>/dev/null || true",
                 "sudo mdutil -i on / 2>/dev/null || true",
             ],
-            run_command,
+            run_command.clone(),
         )),
     ];
 
@@ -389,6 +691,7 @@ async fn main() -> Result<()> {
         style("== Package and System Updates ==").cyan().bold()
     );
     let mut steps: Vec<Box<dyn UpdaterStep + Send + Sync>> = vec![];
+    steps.extend(brew_steps);
     steps.extend(update_steps);
     println!(
         "\n{}",
@@ -398,9 +701,46 @@ async fn main() -> Result<()> {
     );
     steps.extend(maintenance_steps);
 
-    Updater::new(args.interactive, args.quiet, steps, config.clone())
-        .run()
-        .await?;
+    for custom in config.custom_commands.iter().filter(|c| c.enabled) {
+        steps.push(Box::new(CommandStep::new(
+            custom.name.clone(),
+            custom.commands.clone(),
+            run_command.clone(),
+        )));
+    }
+
+    // `Updater::new` filters all steps against `config.skip_steps` by their
+    // `id()`, which for a plugin is its file name, so plugins are excludable
+    // the same way as built-in steps.
+    if let Some(plugins_dir) = plugins::default_plugins_dir() {
+        for path in plugins::discover(&plugins_dir) {
+            steps.push(Box::new(CommandStep::new(
+                plugins::plugin_name(&path),
+                vec![path.display().to_string()],
+                run_command.clone(),
+            )));
+        }
+    }
+
+    let updater = Updater::new(
+        args.interactive,
+        args.quiet,
+        steps,
+        config.clone(),
+        run_log.clone(),
+    );
+
+    // Checked after `Updater::new` has filtered out skipped steps, so a
+    // config that skips the only sudo-using step doesn't needlessly prompt
+    // for sudo or arm the keep-alive loop.
+    let _sudo_keep_alive = if updater.needs_sudo() {
+        sudo::prime();
+        Some(sudo::SudoKeepAlive::spawn())
+    } else {
+        None
+    };
+
+    updater.run().await?;
 
     println!(
         "{}",
@@ -414,5 +754,13 @@ async fn main() -> Result<()> {
         "macOS Maintenance Complete",
         "Your system has been updated and cleaned successfully.",
     )?;
+
+    // Only show the interactive menu in interactive runs: it blocks on a
+    // synchronous keypress, which would hang an unattended (cron/launchd)
+    // non-quiet run forever.
+    if args.interactive && !args.quiet {
+        post_run_menu()?;
+    }
+
     Ok(())
 }
@@ -0,0 +1,84 @@
+//! Discovers drop-in maintenance plugins: any executable file placed in the
+//! plugins directory becomes a step, named after the file, without needing
+//! a recompile or a `custom_commands` entry in the config.
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::steps::is_executable_file;
+
+/// Resolves the default plugins directory, `~/.config/mac-updater/plugins.d`.
+pub fn default_plugins_dir() -> Option<PathBuf> {
+    dirs::home_dir().map(|home| home.join(".config/mac-updater/plugins.d"))
+}
+
+/// Lists the executable files directly inside `dir`, sorted by name so runs
+/// are deterministic. Returns an empty list if the directory doesn't exist.
+pub fn discover(dir: &Path) -> Vec<PathBuf> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return vec![];
+    };
+
+    let mut plugins: Vec<PathBuf> = entries
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| is_executable_file(path))
+        .collect();
+    plugins.sort();
+    plugins
+}
+
+/// The step description a plugin gets: its file name, e.g. `docker-prune.sh`.
+pub fn plugin_name(path: &Path) -> String {
+    path.file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_else(|| path.display().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(unix)]
+    fn write_executable(dir: &Path, name: &str) {
+        use std::os::unix::fs::PermissionsExt;
+        let path = dir.join(name);
+        fs::write(&path, "#!/bin/sh\n").unwrap();
+        fs::set_permissions(&path, fs::Permissions::from_mode(0o755)).unwrap();
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn discover_filters_out_non_executable_files() {
+        let dir = std::env::temp_dir().join(format!(
+            "mac-updater-plugins-test-{:?}",
+            std::thread::current().id()
+        ));
+        fs::create_dir_all(&dir).unwrap();
+        write_executable(&dir, "b-plugin.sh");
+        write_executable(&dir, "a-plugin.sh");
+        fs::write(dir.join("readme.txt"), "not a plugin").unwrap();
+
+        let found = discover(&dir);
+
+        assert_eq!(
+            found,
+            vec![dir.join("a-plugin.sh"), dir.join("b-plugin.sh")]
+        );
+
+        fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn discover_returns_empty_for_a_missing_directory() {
+        let dir = std::env::temp_dir().join("mac-updater-plugins-test-missing-dir");
+        fs::remove_dir_all(&dir).ok();
+
+        assert!(discover(&dir).is_empty());
+    }
+
+    #[test]
+    fn plugin_name_is_the_file_name() {
+        let path = Path::new("/some/dir/docker-prune.sh");
+        assert_eq!(plugin_name(path), "docker-prune.sh");
+    }
+}
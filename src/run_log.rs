@@ -0,0 +1,70 @@
+//! Per-run log file: every command's invocation, exit status and captured
+//! output, plus a final per-step timing summary, so a single run can be
+//! inspected without having to pick it out of the rolling `tracing` log.
+use chrono::Local;
+use std::fs::{self, File};
+use std::io::Write;
+use std::path::PathBuf;
+use std::process::ExitStatus;
+use std::sync::Mutex;
+use std::time::Duration;
+
+pub struct RunLog {
+    file: Mutex<File>,
+    path: PathBuf,
+}
+
+impl RunLog {
+    /// Creates `~/Library/Logs/mac-updater/run-<RFC3339>.log`, falling back
+    /// to `./logs` when the home directory can't be resolved.
+    pub fn create() -> std::io::Result<Self> {
+        let log_dir = dirs::home_dir()
+            .map(|home| home.join("Library/Logs/mac-updater"))
+            .unwrap_or_else(|| PathBuf::from("./logs"));
+        fs::create_dir_all(&log_dir)?;
+
+        let path = log_dir.join(format!("run-{}.log", Local::now().to_rfc3339()));
+        let file = File::create(&path)?;
+
+        Ok(Self {
+            file: Mutex::new(file),
+            path,
+        })
+    }
+
+    pub fn path(&self) -> &PathBuf {
+        &self.path
+    }
+
+    /// Records one shelled-out command's invocation, exit status and full
+    /// stdout/stderr.
+    pub fn record_command(&self, cmd: &str, status: ExitStatus, stdout: &str, stderr: &str) {
+        let mut file = match self.file.lock() {
+            Ok(file) => file,
+            Err(_) => return,
+        };
+
+        let _ = writeln!(
+            file,
+            "[{}] $ {}\nstatus: {}\nstdout:\n{}\nstderr:\n{}\n",
+            Local::now().format("%Y-%m-%d %H:%M:%S"),
+            cmd,
+            status,
+            stdout.trim(),
+            stderr.trim(),
+        );
+    }
+
+    /// Appends the final per-step timing summary.
+    pub fn record_step_durations(&self, durations: &[(String, Duration)]) {
+        let mut file = match self.file.lock() {
+            Ok(file) => file,
+            Err(_) => return,
+        };
+
+        let _ = writeln!(file, "=== Step durations ===");
+        for (desc, duration) in durations {
+            let _ = writeln!(file, "{:>8.2}s  {}", duration.as_secs_f64(), desc);
+        }
+    }
+}
@@ -0,0 +1,100 @@
+//! Resolves which Homebrew installation(s) are present on disk, since the
+//! binary lives at a different prefix on Apple Silicon vs Intel Macs.
+use std::path::Path;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BrewVariant {
+    /// `brew` already resolves on `$PATH`; no specific prefix was detected.
+    Path,
+    MacIntel,
+    MacArm,
+}
+
+impl BrewVariant {
+    /// Path to the `brew` binary for this variant: absolute for the ARM and
+    /// Intel prefixes, the bare name `brew` for `Path`.
+    pub fn binary_name(&self) -> &'static str {
+        match self {
+            BrewVariant::Path => "brew",
+            BrewVariant::MacIntel => "/usr/local/bin/brew",
+            BrewVariant::MacArm => "/opt/homebrew/bin/brew",
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            BrewVariant::Path => "Brew",
+            BrewVariant::MacIntel => "Brew (Intel)",
+            BrewVariant::MacArm => "Brew (ARM)",
+        }
+    }
+
+    /// Stable identifier for `config.skip_steps` matching, kept separate from
+    /// `label()` so the display label can change without silently breaking
+    /// existing configs. `Path` keeps the pre-variant step's original name.
+    pub fn skip_id(&self) -> &'static str {
+        match self {
+            BrewVariant::Path => "Updating Homebrew",
+            BrewVariant::MacIntel => "Updating Homebrew (Intel)",
+            BrewVariant::MacArm => "Updating Homebrew (ARM)",
+        }
+    }
+}
+
+/// Detects which Homebrew prefixes exist on disk. Returns one entry per
+/// installation found, e.g. both ARM and Intel when a machine has both
+/// (common right after migrating to Apple Silicon).
+pub fn detect_variants() -> Vec<BrewVariant> {
+    variants_for(
+        Path::new("/opt/homebrew/bin/brew").exists(),
+        Path::new("/usr/local/bin/brew").exists(),
+    )
+}
+
+/// Pure decision table behind [`detect_variants`], split out so the
+/// ARM/Intel/both/neither matrix can be unit-tested without touching disk.
+fn variants_for(arm_present: bool, intel_present: bool) -> Vec<BrewVariant> {
+    match (arm_present, intel_present) {
+        (true, true) => vec![BrewVariant::MacArm, BrewVariant::MacIntel],
+        (true, false) => vec![BrewVariant::MacArm],
+        (false, true) => vec![BrewVariant::MacIntel],
+        (false, false) => vec![],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn variants_for_covers_the_detection_matrix() {
+        assert_eq!(
+            variants_for(true, true),
+            vec![BrewVariant::MacArm, BrewVariant::MacIntel]
+        );
+        assert_eq!(variants_for(true, false), vec![BrewVariant::MacArm]);
+        assert_eq!(variants_for(false, true), vec![BrewVariant::MacIntel]);
+        assert_eq!(variants_for(false, false), Vec::<BrewVariant>::new());
+    }
+
+    #[test]
+    fn binary_name_matches_the_variant_prefix() {
+        assert_eq!(BrewVariant::Path.binary_name(), "brew");
+        assert_eq!(BrewVariant::MacIntel.binary_name(), "/usr/local/bin/brew");
+        assert_eq!(BrewVariant::MacArm.binary_name(), "/opt/homebrew/bin/brew");
+    }
+
+    #[test]
+    fn label_is_human_readable() {
+        assert_eq!(BrewVariant::Path.label(), "Brew");
+        assert_eq!(BrewVariant::MacIntel.label(), "Brew (Intel)");
+        assert_eq!(BrewVariant::MacArm.label(), "Brew (ARM)");
+    }
+
+    #[test]
+    fn skip_id_is_stable_regardless_of_label() {
+        assert_eq!(BrewVariant::Path.skip_id(), "Updating Homebrew");
+        assert_eq!(BrewVariant::MacIntel.skip_id(), "Updating Homebrew (Intel)");
+        assert_eq!(BrewVariant::MacArm.skip_id(), "Updating Homebrew (ARM)");
+    }
+}
@@ -3,17 +3,103 @@ use anyhow::Result;
 use async_trait::async_trait;
 use console::style;
 use indicatif::ProgressBar;
+use std::env;
+use std::path::Path;
 use tracing::error;
 
+mod brew;
+pub use brew::BrewVariant;
+
+/// Detects the Homebrew installation(s) on this machine. Returns one variant
+/// per prefix found on disk, falling back to a bare `brew` on `$PATH` when
+/// neither the Apple Silicon nor the Intel prefix exists but `brew` still
+/// resolves, and an empty vec when Homebrew isn't installed at all.
+pub fn brew_variants() -> Vec<BrewVariant> {
+    let detected = brew::detect_variants();
+    if !detected.is_empty() {
+        return detected;
+    }
+
+    if binary_exists("brew") {
+        vec![BrewVariant::Path]
+    } else {
+        vec![]
+    }
+}
+
 #[async_trait]
 pub trait UpdaterStep {
     fn description(&self) -> &str;
+
+    /// Stable identifier used to match this step against `config.skip_steps`,
+    /// kept independent of `description()` so a step's display label can
+    /// change without silently breaking existing configs. Defaults to
+    /// `description()`.
+    fn id(&self) -> &str {
+        self.description()
+    }
+
+    /// Executables this step needs on `$PATH` before it can run. Steps that
+    /// only shell out to builtins (e.g. `softwareupdate`) can leave this empty.
+    fn requires(&self) -> &[String] {
+        &[]
+    }
+
+    /// Whether any command in this step shells out through `sudo`. Used to
+    /// decide whether the sudo keep-alive loop needs to be armed.
+    fn uses_sudo(&self) -> bool {
+        false
+    }
+
+    /// Whether this step must run by itself rather than alongside other
+    /// steps. Sudo-using maintenance steps default to exclusive so they
+    /// don't race each other for the sudo timestamp; network-bound update
+    /// steps are independent and safe to run concurrently.
+    fn exclusive(&self) -> bool {
+        self.uses_sudo()
+    }
+
     async fn run(&self, pb: &ProgressBar) -> Result<()>;
 }
 
+/// Checks whether `binary` resolves to an executable file somewhere on `$PATH`,
+/// mirroring what a shell's `which` would report.
+pub fn binary_exists(binary: &str) -> bool {
+    let Some(path_var) = env::var_os("PATH") else {
+        return false;
+    };
+
+    env::split_paths(&path_var).any(|dir| is_executable_file(&dir.join(binary)))
+}
+
+pub(crate) fn is_executable_file(path: &Path) -> bool {
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        path.metadata()
+            .map(|meta| meta.is_file() && meta.permissions().mode() & 0o111 != 0)
+            .unwrap_or(false)
+    }
+    #[cfg(not(unix))]
+    {
+        path.is_file()
+    }
+}
+
+/// Returns the subset of `requires` that could not be resolved on `$PATH`.
+pub fn missing_requirements(requires: &[String]) -> Vec<String> {
+    requires
+        .iter()
+        .filter(|bin| !binary_exists(bin))
+        .cloned()
+        .collect()
+}
+
 pub struct CommandStep {
     description: String,
+    id: Option<String>,
     cmds: Vec<String>,
+    requires: Vec<String>,
     run_command: Box<
         dyn Fn(
                 String,
@@ -39,10 +125,28 @@ impl CommandStep {
     {
         Self {
             description: description.into(),
+            id: None,
             cmds: cmds.into_iter().map(Into::into).collect(),
+            requires: vec![],
             run_command: Box::new(run_command),
         }
     }
+
+    /// Declares the executables this step needs on `$PATH`; the updater will
+    /// report the step as skipped instead of running it when any are missing.
+    pub fn requires<S: Into<String>>(mut self, bins: Vec<S>) -> Self {
+        self.requires = bins.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Overrides the `config.skip_steps` identifier, decoupling it from the
+    /// display label passed to `new`. Use this when the label may change
+    /// (e.g. it's derived from detected hardware) but existing configs must
+    /// keep matching.
+    pub fn id<S: Into<String>>(mut self, id: S) -> Self {
+        self.id = Some(id.into());
+        self
+    }
 }
 
 #[async_trait]
@@ -51,6 +155,18 @@ impl UpdaterStep for CommandStep {
         &self.description
     }
 
+    fn id(&self) -> &str {
+        self.id.as_deref().unwrap_or(&self.description)
+    }
+
+    fn requires(&self) -> &[String] {
+        &self.requires
+    }
+
+    fn uses_sudo(&self) -> bool {
+        self.cmds.iter().any(|cmd| cmd.contains("sudo "))
+    }
+
     async fn run(&self, pb: &ProgressBar) -> Result<()> {
         let total_cmds = self.cmds.len();
         for (i, cmd) in self.cmds.iter().enumerate() {
@@ -78,3 +194,45 @@ impl UpdaterStep for CommandStep {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[cfg(unix)]
+    fn write_executable(dir: &Path, name: &str) {
+        use std::os::unix::fs::PermissionsExt;
+        let path = dir.join(name);
+        fs::write(&path, "#!/bin/sh\n").unwrap();
+        fs::set_permissions(&path, fs::Permissions::from_mode(0o755)).unwrap();
+    }
+
+    // Both cases mutate the process-wide `PATH` env var, so they share a
+    // single test to avoid racing with other tests run in parallel.
+    #[test]
+    #[cfg(unix)]
+    fn resolves_binaries_against_a_scratch_path() {
+        let dir = std::env::temp_dir().join(format!("mac-updater-steps-test-{:?}", std::thread::current().id()));
+        fs::create_dir_all(&dir).unwrap();
+        write_executable(&dir, "present-tool");
+        fs::write(dir.join("not-executable"), "").unwrap();
+
+        let original_path = std::env::var_os("PATH");
+        std::env::set_var("PATH", &dir);
+
+        assert!(binary_exists("present-tool"));
+        assert!(!binary_exists("not-executable"));
+        assert!(!binary_exists("definitely-not-a-real-binary"));
+
+        let requires = vec!["present-tool".to_string(), "absent-tool".to_string()];
+        assert_eq!(missing_requirements(&requires), vec!["absent-tool".to_string()]);
+
+        if let Some(path) = original_path {
+            std::env::set_var("PATH", path);
+        } else {
+            std::env::remove_var("PATH");
+        }
+        fs::remove_dir_all(&dir).ok();
+    }
+}
@@ -0,0 +1,54 @@
+//! Primes `sudo` once up front and keeps the timestamp alive in the
+//! background so long runs don't hit a second, progress-bar-garbling prompt.
+use std::process::Command;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use tokio::task::JoinHandle;
+use tokio::time::{sleep, Duration};
+use tracing::warn;
+
+/// Prompts for the sudo password once, synchronously, before any progress
+/// bars are drawn. A failure to prime is only logged, so the run can
+/// continue; individual sudo commands will simply prompt (and may garble
+/// the UI) or fail on their own.
+pub fn prime() {
+    if let Err(e) = Command::new("sudo").arg("-v").status() {
+        warn!("Failed to prime sudo credentials: {}", e);
+    }
+}
+
+/// Handle to the background keep-alive task. Dropping it stops the loop.
+pub struct SudoKeepAlive {
+    cancelled: Arc<AtomicBool>,
+    task: JoinHandle<()>,
+}
+
+impl SudoKeepAlive {
+    /// Spawns a task that refreshes the sudo timestamp every 60 seconds
+    /// until the guard is dropped.
+    pub fn spawn() -> Self {
+        let cancelled = Arc::new(AtomicBool::new(false));
+        let loop_cancelled = cancelled.clone();
+
+        let task = tokio::spawn(async move {
+            while !loop_cancelled.load(Ordering::Relaxed) {
+                sleep(Duration::from_secs(60)).await;
+                if loop_cancelled.load(Ordering::Relaxed) {
+                    break;
+                }
+                if let Err(e) = Command::new("sudo").arg("-n").arg("-v").status() {
+                    warn!("Failed to refresh sudo timestamp: {}", e);
+                }
+            }
+        });
+
+        Self { cancelled, task }
+    }
+}
+
+impl Drop for SudoKeepAlive {
+    fn drop(&mut self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+        self.task.abort();
+    }
+}
@@ -0,0 +1,32 @@
+//! Per-step timing, recorded even when a step fails.
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+pub type StepDurations = Arc<Mutex<Vec<(String, Duration)>>>;
+
+/// Captures elapsed time on `Drop`, so a step that panics or errors out
+/// partway through still gets timed.
+pub struct StepTimer {
+    description: String,
+    start: Instant,
+    durations: StepDurations,
+}
+
+impl StepTimer {
+    pub fn start(description: impl Into<String>, durations: StepDurations) -> Self {
+        Self {
+            description: description.into(),
+            start: Instant::now(),
+            durations,
+        }
+    }
+}
+
+impl Drop for StepTimer {
+    fn drop(&mut self) {
+        let elapsed = self.start.elapsed();
+        if let Ok(mut durations) = self.durations.lock() {
+            durations.push((self.description.clone(), elapsed));
+        }
+    }
+}